@@ -0,0 +1,143 @@
+use futures::stream::TryStreamExt;
+use mongodb::bson::{doc, oid::ObjectId};
+use mongodb::options::{ClientOptions, FindOptions};
+use mongodb::{Client, Collection, Database};
+
+use crate::config::Config;
+use crate::migration;
+use crate::models::{Book, BookPage, BookQuery, Genre, SortDirection};
+
+/// A thin, typed wrapper around the book club's MongoDB database.
+///
+/// Holds a handle to the connected `mongodb::Database` and exposes async methods for the
+/// `genres` and `books` collections so callers never have to build `doc!`
+/// filters by hand.
+pub struct BookClubClient {
+    db: Database,
+}
+
+impl BookClubClient {
+    /// Connects using `config`, pings the server, and returns a ready-to-use
+    /// client. The database is taken from `config.database`, falling back to
+    /// the database encoded in the connection URI, and then to `"book_club"`.
+    pub async fn new(config: Config) -> mongodb::error::Result<Self> {
+        let mut client_options = ClientOptions::parse(&config.uri).await?;
+        client_options.app_name = Some("book-club".to_string());
+        client_options.max_pool_size = config.max_pool_size;
+        client_options.connect_timeout = config.connect_timeout;
+        client_options.server_selection_timeout = config.server_selection_timeout;
+
+        let default_db = client_options
+            .default_database
+            .clone()
+            .unwrap_or_else(|| "book_club".to_string());
+
+        let client = Client::with_options(client_options)?;
+
+        client
+            .database("admin")
+            .run_command(doc! { "ping": 1 }, None)
+            .await?;
+
+        let db = client.database(&config.database.unwrap_or(default_db));
+        migration::run(&db).await?;
+        Ok(Self { db })
+    }
+
+    fn genres(&self) -> Collection<Genre> {
+        self.db.collection::<Genre>("genres")
+    }
+
+    fn books(&self) -> Collection<Book> {
+        self.db.collection::<Book>("books")
+    }
+
+    /// Returns every genre in the collection.
+    pub async fn list_genres(&self) -> mongodb::error::Result<Vec<Genre>> {
+        let mut cursor = self.genres().find(None, None).await?;
+        let mut genres = Vec::new();
+        while let Some(genre) = cursor.try_next().await? {
+            genres.push(genre);
+        }
+        Ok(genres)
+    }
+
+    /// Inserts a new genre and returns it with its assigned `_id`.
+    pub async fn add_genre(&self, mut genre: Genre) -> mongodb::error::Result<Genre> {
+        let result = self.genres().insert_one(&genre, None).await?;
+        genre.id = result.inserted_id.as_object_id();
+        Ok(genre)
+    }
+
+    /// Looks up a genre by its exact name.
+    pub async fn find_genre_by_name(&self, name: &str) -> mongodb::error::Result<Option<Genre>> {
+        self.genres().find_one(doc! { "name": name }, None).await
+    }
+
+    /// Inserts a new book and returns it with its assigned `_id`.
+    pub async fn add_book(&self, mut book: Book) -> mongodb::error::Result<Book> {
+        let result = self.books().insert_one(&book, None).await?;
+        book.id = result.inserted_id.as_object_id();
+        Ok(book)
+    }
+
+    /// Looks up a book by its `_id`.
+    pub async fn find_book_by_id(&self, id: ObjectId) -> mongodb::error::Result<Option<Book>> {
+        self.books().find_one(doc! { "_id": id }, None).await
+    }
+
+    /// Returns a filtered, sorted, paginated page of books along with the
+    /// total count of books matching the filter (ignoring `skip`/`limit`).
+    pub async fn list_books(&self, query: BookQuery) -> mongodb::error::Result<BookPage> {
+        let mut filter = doc! {};
+        if let Some(genre) = query.genre {
+            filter.insert("genre_ids", genre);
+        }
+        if let Some(author) = query.author {
+            filter.insert("author", doc! { "$regex": author, "$options": "i" });
+        }
+
+        let total = self.books().count_documents(filter.clone(), None).await?;
+
+        let sort = query.sort_by.map(|sort_by| {
+            let direction = match query.sort_direction.unwrap_or(SortDirection::Ascending) {
+                SortDirection::Ascending => 1,
+                SortDirection::Descending => -1,
+            };
+            doc! { sort_by: direction }
+        });
+        let options = FindOptions::builder()
+            .sort(sort)
+            .skip(query.skip)
+            .limit(query.limit)
+            .build();
+
+        let mut cursor = self.books().find(filter, options).await?;
+        let mut books = Vec::new();
+        while let Some(book) = cursor.try_next().await? {
+            books.push(book);
+        }
+        Ok(BookPage { books, total })
+    }
+
+    /// Updates the title, author and genres of the book with the given `id`.
+    pub async fn update_book(&self, id: ObjectId, book: Book) -> mongodb::error::Result<()> {
+        let update = doc! {
+            "$set": {
+                "title": book.title,
+                "author": book.author,
+                "genre_ids": book.genre_ids,
+            }
+        };
+        self.books()
+            .update_one(doc! { "_id": id }, update, None)
+            .await?;
+        Ok(())
+    }
+
+    /// Deletes the book with the given `id`.
+    pub async fn delete_book(&self, id: ObjectId) -> mongodb::error::Result<()> {
+        self.books().delete_one(doc! { "_id": id }, None).await?;
+        Ok(())
+    }
+}
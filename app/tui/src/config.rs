@@ -0,0 +1,33 @@
+use std::env;
+use std::time::Duration;
+
+/// Connection settings for [`BookClubClient`](crate::client::BookClubClient),
+/// loaded from environment variables so the same binary can target
+/// dev/staging/prod clusters without recompiling.
+pub struct Config {
+    pub uri: String,
+    pub database: Option<String>,
+    pub max_pool_size: Option<u32>,
+    pub connect_timeout: Option<Duration>,
+    pub server_selection_timeout: Option<Duration>,
+}
+
+impl Config {
+    /// Reads `MONGODB_URI` (required) and the optional tuning variables
+    /// `MONGODB_DATABASE`, `MONGODB_MAX_POOL_SIZE`, `MONGODB_CONNECT_TIMEOUT_MS`
+    /// and `MONGODB_SERVER_SELECTION_TIMEOUT_MS` from the environment.
+    pub fn from_env() -> Self {
+        Self {
+            uri: env::var("MONGODB_URI").expect("MONGODB_URI not set"),
+            database: env::var("MONGODB_DATABASE").ok(),
+            max_pool_size: env_parse("MONGODB_MAX_POOL_SIZE"),
+            connect_timeout: env_parse("MONGODB_CONNECT_TIMEOUT_MS").map(Duration::from_millis),
+            server_selection_timeout: env_parse("MONGODB_SERVER_SELECTION_TIMEOUT_MS")
+                .map(Duration::from_millis),
+        }
+    }
+}
+
+fn env_parse<T: std::str::FromStr>(key: &str) -> Option<T> {
+    env::var(key).ok().and_then(|value| value.parse().ok())
+}
@@ -0,0 +1,9 @@
+pub mod client;
+pub mod config;
+pub mod migration;
+pub mod models;
+#[cfg(feature = "server")]
+pub mod server;
+
+pub use client::BookClubClient;
+pub use config::Config;
@@ -1,40 +1,33 @@
-use mongodb::{Client, options::ClientOptions, bson::{doc, Document}};
+use book_club::{BookClubClient, Config};
 use dotenv::dotenv;
-use std::env;
-use tokio;
-use futures::stream::TryStreamExt;
 
 #[tokio::main]
 async fn main() -> mongodb::error::Result<()> {
     // Load environment variables from .env file
     dotenv().ok();
 
-    // Get MongoDB URI from environment
-    let uri = env::var("MONGODB_URI").expect("MONGODB_URI not set");
-
-    // Parse options and create client
-    let client_options = ClientOptions::parse(&uri).await?;
-    let client = Client::with_options(client_options)?;
-
-    // Ping the server to confirm connection
-    client
-        .database("admin")
-        .run_command(doc! { "ping": 1 }, None)
-        .await?;
-
+    let client = BookClubClient::new(Config::from_env()).await?;
     println!("Connected to MongoDB!");
 
-    // Access the 'book_club' database and 'books' collection
-    let db = client.database("book_club");
-    let collection = db.collection::<Document>("genres");
+    run(client).await
+}
 
-    // Query all documents
-    let mut cursor = collection.find(None, None).await?;
+#[cfg(feature = "server")]
+async fn run(client: BookClubClient) -> mongodb::error::Result<()> {
+    let addr = "0.0.0.0:3000".parse().expect("invalid server address");
+    println!("Serving book club API on {}", addr);
+    axum::Server::bind(&addr)
+        .serve(book_club::server::router(client).into_make_service())
+        .await
+        .expect("server error");
+    Ok(())
+}
 
+#[cfg(not(feature = "server"))]
+async fn run(client: BookClubClient) -> mongodb::error::Result<()> {
     println!("Genres in collection:");
-    while let Some(book) = cursor.try_next().await? {
-        println!("{:#?}", book);
+    for genre in client.list_genres().await? {
+        println!("{:#?}", genre);
     }
-
     Ok(())
 }
@@ -0,0 +1,68 @@
+use mongodb::bson::doc;
+use mongodb::options::IndexOptions;
+use mongodb::{Database, IndexModel};
+
+/// A single idempotent migration step, identified by `name`, applied via
+/// `apply` and recorded in the `_migrations` collection so it never runs
+/// twice.
+struct Migration {
+    name: &'static str,
+    apply: fn(&Database) -> futures::future::BoxFuture<'_, mongodb::error::Result<()>>,
+}
+
+/// Ensures the collections the app relies on exist with the right indexes.
+///
+/// Each migration is checked against the `_migrations` collection by name
+/// before it runs, so re-running this on an already-migrated database is a
+/// no-op.
+pub async fn run(db: &Database) -> mongodb::error::Result<()> {
+    let migrations = [
+        Migration {
+            name: "genres_name_unique_index",
+            apply: |db| Box::pin(create_genres_name_index(db)),
+        },
+        Migration {
+            name: "books_title_index",
+            apply: |db| Box::pin(create_books_title_index(db)),
+        },
+    ];
+
+    let applied = db.collection::<mongodb::bson::Document>("_migrations");
+
+    for migration in migrations {
+        if applied
+            .find_one(doc! { "name": migration.name }, None)
+            .await?
+            .is_some()
+        {
+            continue;
+        }
+
+        (migration.apply)(db).await?;
+
+        applied
+            .insert_one(doc! { "name": migration.name }, None)
+            .await?;
+    }
+
+    Ok(())
+}
+
+async fn create_genres_name_index(db: &Database) -> mongodb::error::Result<()> {
+    let index = IndexModel::builder()
+        .keys(doc! { "name": 1 })
+        .options(IndexOptions::builder().unique(true).build())
+        .build();
+    db.collection::<mongodb::bson::Document>("genres")
+        .create_index(index, None)
+        .await?;
+    Ok(())
+}
+
+async fn create_books_title_index(db: &Database) -> mongodb::error::Result<()> {
+    let index = IndexModel::builder().keys(doc! { "title": 1 }).build();
+    db.collection::<mongodb::bson::Document>("books")
+        .create_index(index, None)
+        .await?;
+    Ok(())
+}
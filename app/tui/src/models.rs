@@ -0,0 +1,46 @@
+use mongodb::bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+
+/// A literary genre, e.g. "Science Fiction" or "Historical Fiction".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Genre {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// A book tracked by the club, linked to one or more genres.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Book {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+    pub title: String,
+    pub author: String,
+    pub genre_ids: Vec<ObjectId>,
+}
+
+/// Direction to sort a [`BookQuery`] by.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// Filter, sort and pagination parameters for listing books.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BookQuery {
+    pub genre: Option<ObjectId>,
+    pub author: Option<String>,
+    pub sort_by: Option<String>,
+    pub sort_direction: Option<SortDirection>,
+    pub skip: Option<u64>,
+    pub limit: Option<i64>,
+}
+
+/// A page of books plus the total count matching the query's filter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookPage {
+    pub books: Vec<Book>,
+    pub total: u64,
+}
@@ -0,0 +1,125 @@
+//! HTTP/JSON front-end for [`BookClubClient`], gated behind the `server`
+//! feature so the plain CLI build doesn't pull in an async web framework.
+
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use mongodb::bson::oid::ObjectId;
+
+use crate::client::BookClubClient;
+use crate::models::{Book, BookPage, BookQuery, Genre, SortDirection};
+
+type SharedClient = Arc<BookClubClient>;
+
+/// Builds the router exposing the genres and books collections over HTTP.
+pub fn router(client: BookClubClient) -> Router {
+    let state: SharedClient = Arc::new(client);
+
+    Router::new()
+        .route("/genres", get(list_genres).post(add_genre))
+        .route("/books", get(list_books).post(add_book))
+        .route("/books/:id", get(get_book).delete(delete_book))
+        .with_state(state)
+}
+
+async fn list_genres(State(client): State<SharedClient>) -> Result<Json<Vec<Genre>>, ApiError> {
+    Ok(Json(client.list_genres().await?))
+}
+
+async fn add_genre(
+    State(client): State<SharedClient>,
+    Json(genre): Json<Genre>,
+) -> Result<Json<Genre>, ApiError> {
+    Ok(Json(client.add_genre(genre).await?))
+}
+
+/// Query-string shape for `GET /books`. `genre` arrives as a bare hex string
+/// and is parsed into an `ObjectId` before being handed to `BookQuery`, since
+/// `serde_urlencoded` can't deserialize an `ObjectId` directly from a param.
+#[derive(serde::Deserialize)]
+struct BookQueryParams {
+    genre: Option<String>,
+    author: Option<String>,
+    sort_by: Option<String>,
+    sort_direction: Option<SortDirection>,
+    skip: Option<u64>,
+    limit: Option<i64>,
+}
+
+async fn list_books(
+    State(client): State<SharedClient>,
+    Query(params): Query<BookQueryParams>,
+) -> Result<Json<BookPage>, ApiError> {
+    let genre = params.genre.as_deref().map(parse_id).transpose()?;
+    let query = BookQuery {
+        genre,
+        author: params.author,
+        sort_by: params.sort_by,
+        sort_direction: params.sort_direction,
+        skip: params.skip,
+        limit: params.limit,
+    };
+    Ok(Json(client.list_books(query).await?))
+}
+
+async fn add_book(
+    State(client): State<SharedClient>,
+    Json(book): Json<Book>,
+) -> Result<Json<Book>, ApiError> {
+    Ok(Json(client.add_book(book).await?))
+}
+
+async fn get_book(
+    State(client): State<SharedClient>,
+    Path(id): Path<String>,
+) -> Result<Json<Book>, ApiError> {
+    let id = parse_id(&id)?;
+    let book = client
+        .find_book_by_id(id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    Ok(Json(book))
+}
+
+async fn delete_book(
+    State(client): State<SharedClient>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let id = parse_id(&id)?;
+    client.delete_book(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn parse_id(id: &str) -> Result<ObjectId, ApiError> {
+    ObjectId::parse_str(id).map_err(|_| ApiError::InvalidId)
+}
+
+/// Errors an HTTP handler can return, mapped to the appropriate status code.
+enum ApiError {
+    Mongo(mongodb::error::Error),
+    InvalidId,
+    NotFound,
+}
+
+impl From<mongodb::error::Error> for ApiError {
+    fn from(err: mongodb::error::Error) -> Self {
+        ApiError::Mongo(err)
+    }
+}
+
+impl axum::response::IntoResponse for ApiError {
+    fn into_response(self) -> axum::response::Response {
+        let status = match &self {
+            ApiError::Mongo(err) => {
+                eprintln!("mongodb error: {err}");
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+            ApiError::InvalidId => StatusCode::BAD_REQUEST,
+            ApiError::NotFound => StatusCode::NOT_FOUND,
+        };
+        status.into_response()
+    }
+}